@@ -4,9 +4,19 @@ mod sys;
 #[cfg(windows)]
 #[path = "windows.rs"]
 mod sys;
+// `libc`'s WASI socket bindings only exist for `wasm32-wasip2`/`wasip3`
+// (`cfg(not(target_env = "p1"))`); `wasm32-wasip1` gets its own backend since
+// `wasi-libc` doesn't wire up `socket(2)` and friends there at all.
+#[cfg(all(target_os = "wasi", not(target_env = "p1")))]
+#[path = "wasi.rs"]
+mod sys;
+#[cfg(all(target_os = "wasi", target_env = "p1"))]
+#[path = "wasi_p1.rs"]
+mod sys;
 
 use std::io;
-use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6, TcpStream};
+use std::net::{SocketAddr, SocketAddrV4, SocketAddrV6, TcpStream, UdpSocket};
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct Domain(sys::c_int);
@@ -31,6 +41,11 @@ impl Type {
     pub fn stream() -> Self {
         Type(sys::SOCK_STREAM)
     }
+
+    // Used for protocols such as UDP.
+    pub fn dgram() -> Self {
+        Type(sys::SOCK_DGRAM)
+    }
 }
 
 #[derive(Debug)]
@@ -41,6 +56,11 @@ impl Protocol {
     pub fn tcp() -> Self {
         Self(sys::IPPROTO_TCP)
     }
+
+    // Protocol corresponding to `UDP`.
+    pub fn udp() -> Self {
+        Self(sys::IPPROTO_UDP)
+    }
 }
 
 // `Addr`s may be constructed directly to and from the standard library
@@ -132,6 +152,63 @@ impl Socket {
     pub fn connect(&self, addr: impl Into<Addr>) -> io::Result<()> {
         self.0.connect(addr.into())
     }
+
+    // Like `connect`, but waits for the connection to complete or fail up to
+    // the given `timeout` rather than returning immediately with `EINPROGRESS`
+    // / `WSAEWOULDBLOCK`.
+    pub fn connect_timeout(&self, addr: impl Into<Addr>, timeout: Duration) -> io::Result<()> {
+        self.0.connect_timeout(addr.into(), timeout)
+    }
+
+    // This function directly corresponds to the bind(2) function on Windows
+    // and Unix.
+    pub fn bind(&self, addr: impl Into<Addr>) -> io::Result<()> {
+        self.0.bind(addr.into())
+    }
+
+    // This function directly corresponds to the listen(2) function on
+    // Windows and Unix.
+    pub fn listen(&self, backlog: i32) -> io::Result<()> {
+        self.0.listen(backlog)
+    }
+
+    // Accepts a new incoming connection, returning the connected, nonblocking
+    // `Socket` along with the address of the peer.
+    pub fn accept(&self) -> io::Result<(Self, Addr)> {
+        let (socket, addr) = self.0.accept()?;
+        Ok((Self(socket), addr))
+    }
+
+    // Sets the `SO_REUSEADDR` option for this socket.
+    pub fn set_reuse_address(&self, reuse: bool) -> io::Result<()> {
+        self.0.set_reuse_address(reuse)
+    }
+
+    // Sets the `TCP_NODELAY` option for this socket.
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    // Sets the `SO_KEEPALIVE` option for this socket, along with the
+    // keepalive idle time when `Some`.
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    // Sets the `IP_TTL` option for this socket.
+    pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
+        self.0.set_ttl(ttl)
+    }
+
+    // Sets the `SO_RCVBUF` option for this socket.
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    // Sets the `SO_SNDBUF` option for this socket.
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
 }
 
 impl From<Socket> for TcpStream {
@@ -140,9 +217,25 @@ impl From<Socket> for TcpStream {
     }
 }
 
+impl From<Socket> for UdpSocket {
+    fn from(socket: Socket) -> Self {
+        Self::from(socket.0)
+    }
+}
+
 #[cfg(unix)]
 impl From<Socket> for std::os::unix::net::UnixStream {
     fn from(socket: Socket) -> Self {
         Self::from(socket.0)
     }
 }
+
+// The standard library has no Windows `UnixStream`, so the connected
+// `AF_UNIX` socket is exposed as a raw socket for callers to wrap themselves.
+#[cfg(windows)]
+impl std::os::windows::io::IntoRawSocket for Socket {
+    fn into_raw_socket(self) -> std::os::windows::io::RawSocket {
+        use std::os::windows::io::IntoRawSocket as _;
+        self.0.into_raw_socket()
+    }
+}
@@ -1,8 +1,10 @@
 pub use libc::c_int;
 use std::io;
-use std::net::TcpStream;
+use std::mem::MaybeUninit;
+use std::net::{TcpStream, UdpSocket};
 use std::os::unix::net::UnixStream;
 use std::os::unix::prelude::{AsRawFd, FromRawFd, IntoRawFd};
+use std::time::{Duration, Instant};
 
 use super::{Addr, Domain};
 
@@ -11,9 +13,9 @@ pub use libc::{sockaddr, sockaddr_storage, socklen_t};
 // Used in `Domain`.
 pub use libc::{AF_INET, AF_INET6};
 // Used in `Type`.
-pub use libc::SOCK_STREAM;
+pub use libc::{SOCK_DGRAM, SOCK_STREAM};
 // Used in `Protocol`.
-pub use libc::IPPROTO_TCP;
+pub use libc::{IPPROTO_TCP, IPPROTO_UDP};
 
 impl Domain {
     // Domain for Unix socket communication.
@@ -131,6 +133,168 @@ impl Socket {
             .error()
             .map(drop)
     }
+
+    pub fn bind(&self, addr: Addr) -> io::Result<()> {
+        unsafe { libc::bind(self.0, addr.as_ptr(), addr.len()) }
+            .error()
+            .map(drop)
+    }
+
+    pub fn listen(&self, backlog: c_int) -> io::Result<()> {
+        unsafe { libc::listen(self.0, backlog) }.error().map(drop)
+    }
+
+    #[cfg(target_os = "linux")]
+    pub fn accept(&self) -> io::Result<(Self, Addr)> {
+        unsafe {
+            let mut storage = MaybeUninit::<sockaddr_storage>::uninit();
+            let mut len = std::mem::size_of::<sockaddr_storage>() as socklen_t;
+            // Atomically accept and set the CLOEXEC/NONBLOCK flags, just as
+            // `Socket::new` does on Linux.
+            let fd = libc::accept4(
+                self.0,
+                storage.as_mut_ptr() as *mut _,
+                &mut len,
+                libc::SOCK_CLOEXEC | libc::SOCK_NONBLOCK,
+            )
+            .error()?;
+
+            let addr = Addr::from_raw_parts(storage.as_ptr() as *const _, len);
+            Ok((Self::from_raw_fd(fd), addr))
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn accept(&self) -> io::Result<(Self, Addr)> {
+        unsafe {
+            let mut storage = MaybeUninit::<sockaddr_storage>::uninit();
+            let mut len = std::mem::size_of::<sockaddr_storage>() as socklen_t;
+            let fd = libc::accept(self.0, storage.as_mut_ptr() as *mut _, &mut len).error()?;
+
+            // Set close-on-exec flag.
+            let flags = libc::fcntl(fd, libc::F_GETFD).error()? | libc::FD_CLOEXEC;
+            libc::fcntl(fd, libc::F_SETFD, flags).error()?;
+            // Put socket into nonblocking mode.
+            let flags = libc::fcntl(fd, libc::F_GETFL).error()? | libc::O_NONBLOCK;
+            libc::fcntl(fd, libc::F_SETFL, flags).error()?;
+
+            let addr = Addr::from_raw_parts(storage.as_ptr() as *const _, len);
+            Ok((Self::from_raw_fd(fd), addr))
+        }
+    }
+
+    pub fn connect_timeout(&self, addr: Addr, timeout: Duration) -> io::Result<()> {
+        match self.connect(addr) {
+            Ok(()) => return Ok(()),
+            Err(e)
+                if e.raw_os_error() == Some(libc::EINPROGRESS)
+                    || e.raw_os_error() == Some(libc::EINTR) => {}
+            Err(e) => return Err(e),
+        }
+
+        let mut remaining = timeout;
+        loop {
+            let mut pollfd = libc::pollfd {
+                fd: self.0,
+                events: libc::POLLOUT,
+                revents: 0,
+            };
+
+            let start = Instant::now();
+            let ret = unsafe { libc::poll(&mut pollfd, 1, remaining.as_millis() as c_int) };
+            remaining = remaining.saturating_sub(start.elapsed());
+
+            match ret {
+                -1 if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted => {
+                    if remaining.is_zero() {
+                        return Err(io::ErrorKind::TimedOut.into());
+                    }
+                }
+                -1 => return Err(io::Error::last_os_error()),
+                0 => return Err(io::ErrorKind::TimedOut.into()),
+                _ => break,
+            }
+        }
+
+        self.take_error()
+    }
+
+    fn take_error(&self) -> io::Result<()> {
+        unsafe {
+            let mut err: c_int = 0;
+            let mut len = std::mem::size_of::<c_int>() as libc::socklen_t;
+            libc::getsockopt(
+                self.0,
+                libc::SOL_SOCKET,
+                libc::SO_ERROR,
+                &mut err as *mut c_int as *mut libc::c_void,
+                &mut len,
+            )
+            .error()?;
+
+            if err == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::from_raw_os_error(err))
+            }
+        }
+    }
+
+    pub fn set_reuse_address(&self, reuse: bool) -> io::Result<()> {
+        self.setsockopt(libc::SOL_SOCKET, libc::SO_REUSEADDR, reuse as c_int)
+    }
+
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.setsockopt(libc::IPPROTO_TCP, libc::TCP_NODELAY, nodelay as c_int)
+    }
+
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.setsockopt(
+            libc::SOL_SOCKET,
+            libc::SO_KEEPALIVE,
+            keepalive.is_some() as c_int,
+        )?;
+        // OpenBSD has neither `TCP_KEEPIDLE` nor `TCP_KEEPALIVE`, so it only
+        // gets the on/off toggle above.
+        #[cfg(not(target_os = "openbsd"))]
+        if let Some(time) = keepalive {
+            // Apple platforms don't have `TCP_KEEPIDLE`; the equivalent
+            // option is `TCP_KEEPALIVE` there.
+            #[cfg(any(target_os = "macos", target_os = "ios"))]
+            let keepidle = libc::TCP_KEEPALIVE;
+            #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+            let keepidle = libc::TCP_KEEPIDLE;
+
+            self.setsockopt(libc::IPPROTO_TCP, keepidle, time.as_secs() as c_int)?;
+        }
+        Ok(())
+    }
+
+    pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
+        self.setsockopt(libc::IPPROTO_IP, libc::IP_TTL, ttl as c_int)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.setsockopt(libc::SOL_SOCKET, libc::SO_RCVBUF, size as c_int)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.setsockopt(libc::SOL_SOCKET, libc::SO_SNDBUF, size as c_int)
+    }
+
+    fn setsockopt(&self, level: c_int, name: c_int, value: c_int) -> io::Result<()> {
+        unsafe {
+            libc::setsockopt(
+                self.0,
+                level,
+                name,
+                &value as *const c_int as *const libc::c_void,
+                std::mem::size_of::<c_int>() as libc::socklen_t,
+            )
+        }
+        .error()
+        .map(drop)
+    }
 }
 
 impl Drop for Socket {
@@ -173,6 +337,12 @@ impl From<Socket> for UnixStream {
     }
 }
 
+impl From<Socket> for UdpSocket {
+    fn from(socket: Socket) -> Self {
+        unsafe { Self::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
 trait ToError: Sized {
     fn error(self) -> io::Result<Self>;
 }
@@ -0,0 +1,133 @@
+pub use libc::c_int;
+use std::io;
+use std::net::{TcpStream, UdpSocket};
+use std::os::wasi::prelude::{AsRawFd, FromRawFd, IntoRawFd};
+use std::time::Duration;
+
+use super::Addr;
+
+// Used in `Addr`
+pub use libc::{sockaddr, sockaddr_storage, socklen_t};
+// Used in `Domain`.
+pub use libc::{AF_INET, AF_INET6};
+// Used in `Type`.
+pub use libc::{SOCK_DGRAM, SOCK_STREAM};
+// Used in `Protocol`.
+pub use libc::{IPPROTO_TCP, IPPROTO_UDP};
+
+#[derive(Debug)]
+pub struct Socket(c_int);
+
+impl Socket {
+    // This file only builds for `wasm32-wasip2`/`wasip3`
+    // (`cfg(not(target_env = "p1"))`); see `wasi_p1.rs` for
+    // `wasm32-wasip1`, where `libc` exposes no `socket(2)` at all.
+    pub fn new(_family: c_int, _ty: c_int, _protocol: c_int) -> io::Result<Self> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn connect(&self, addr: Addr) -> io::Result<()> {
+        unsafe { libc::connect(self.0, addr.as_ptr(), addr.len()) }
+            .error()
+            .map(drop)
+    }
+
+    pub fn bind(&self, _addr: Addr) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn listen(&self, _backlog: c_int) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn accept(&self) -> io::Result<(Self, Addr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn connect_timeout(&self, _addr: Addr, _timeout: Duration) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_reuse_address(&self, _reuse: bool) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_nodelay(&self, _nodelay: bool) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_keepalive(&self, _keepalive: Option<Duration>) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_ttl(&self, _ttl: u32) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_recv_buffer_size(&self, _size: usize) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_send_buffer_size(&self, _size: usize) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = libc::close(self.0);
+        }
+    }
+}
+
+impl FromRawFd for Socket {
+    unsafe fn from_raw_fd(fd: c_int) -> Self {
+        Self(fd)
+    }
+}
+
+impl AsRawFd for Socket {
+    fn as_raw_fd(&self) -> c_int {
+        self.0
+    }
+}
+
+impl IntoRawFd for Socket {
+    fn into_raw_fd(self) -> c_int {
+        let fd = self.0;
+        std::mem::forget(self);
+        fd
+    }
+}
+
+impl From<Socket> for TcpStream {
+    fn from(socket: Socket) -> Self {
+        unsafe { Self::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
+impl From<Socket> for UdpSocket {
+    fn from(socket: Socket) -> Self {
+        unsafe { Self::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
+trait ToError: Sized {
+    fn error(self) -> io::Result<Self>;
+}
+
+macro_rules! impl_is_error {
+    ($($t:ident)*) => ($(impl ToError for $t {
+        fn error(self) -> io::Result<Self> {
+            if self == -1 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(self)
+            }
+
+        }
+    })*)
+}
+
+impl_is_error! { i8 i16 i32 i64 isize }
@@ -0,0 +1,134 @@
+pub use libc::c_int;
+use std::io;
+use std::net::{TcpStream, UdpSocket};
+use std::os::wasi::prelude::{AsRawFd, FromRawFd, IntoRawFd};
+use std::time::Duration;
+
+use super::Addr;
+
+// `wasm32-wasip1` has no `socket(2)` family at all, and `libc` only exposes
+// the BSD socket types/constants it mirrors (`sockaddr`, `sockaddr_storage`,
+// `AF_INET`, ...) for `wasm32-wasip2`/`wasip3`. They're redefined here to
+// match `wasi-libc`'s `<sys/socket.h>` so the shared `Addr`/`Domain`/`Type`
+// code builds unchanged, even though every socket operation below is
+// unsupported on this target.
+pub type sa_family_t = u16;
+pub type socklen_t = u32;
+
+#[repr(C, align(16))]
+pub struct sockaddr {
+    pub sa_family: sa_family_t,
+    pub sa_data: [u8; 0],
+}
+
+#[repr(C, align(16))]
+pub struct sockaddr_storage {
+    pub ss_family: sa_family_t,
+    pub __ss_data: [u8; 32],
+}
+
+// Used in `Domain`.
+pub const AF_INET: c_int = 1;
+pub const AF_INET6: c_int = 2;
+// Used in `Type`.
+pub const SOCK_DGRAM: c_int = 5;
+pub const SOCK_STREAM: c_int = 6;
+// Used in `Protocol`.
+pub const IPPROTO_TCP: c_int = 6;
+pub const IPPROTO_UDP: c_int = 17;
+
+#[derive(Debug)]
+pub struct Socket(c_int);
+
+impl Socket {
+    // WASI preview1 can't create sockets from scratch, and unlike preview2
+    // `libc` has no raw `sock_*` syscalls to fall back to, so every
+    // operation here simply reports unsupported.
+    pub fn new(_family: c_int, _ty: c_int, _protocol: c_int) -> io::Result<Self> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn connect(&self, _addr: Addr) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn bind(&self, _addr: Addr) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn listen(&self, _backlog: c_int) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn accept(&self) -> io::Result<(Self, Addr)> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn connect_timeout(&self, _addr: Addr, _timeout: Duration) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_reuse_address(&self, _reuse: bool) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_nodelay(&self, _nodelay: bool) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_keepalive(&self, _keepalive: Option<Duration>) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_ttl(&self, _ttl: u32) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_recv_buffer_size(&self, _size: usize) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    pub fn set_send_buffer_size(&self, _size: usize) -> io::Result<()> {
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = libc::close(self.0);
+        }
+    }
+}
+
+impl FromRawFd for Socket {
+    unsafe fn from_raw_fd(fd: c_int) -> Self {
+        Self(fd)
+    }
+}
+
+impl AsRawFd for Socket {
+    fn as_raw_fd(&self) -> c_int {
+        self.0
+    }
+}
+
+impl IntoRawFd for Socket {
+    fn into_raw_fd(self) -> c_int {
+        let fd = self.0;
+        std::mem::forget(self);
+        fd
+    }
+}
+
+impl From<Socket> for TcpStream {
+    fn from(socket: Socket) -> Self {
+        unsafe { Self::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
+
+impl From<Socket> for UdpSocket {
+    fn from(socket: Socket) -> Self {
+        unsafe { Self::from_raw_fd(socket.into_raw_fd()) }
+    }
+}
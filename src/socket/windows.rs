@@ -1,30 +1,107 @@
 use std::io;
+use std::mem::MaybeUninit;
 use std::net::{TcpStream, UdpSocket};
 use std::os::windows::prelude::{AsRawSocket, FromRawSocket, IntoRawSocket, RawSocket};
 use std::ptr;
 use std::sync::Once;
+use std::time::{Duration, Instant};
 pub use winapi::ctypes::c_int;
 use winapi::ctypes::c_ulong;
 use winapi::shared::minwindef::DWORD;
+use winapi::shared::mstcpip::{tcp_keepalive, SIO_KEEPALIVE_VALS};
 use winapi::shared::ntdef::HANDLE;
+use winapi::shared::ws2def::{
+    SOL_SOCKET, SO_ERROR, SO_KEEPALIVE, SO_RCVBUF, SO_REUSEADDR, SO_SNDBUF,
+};
+use winapi::shared::ws2ipdef::IP_TTL;
 use winapi::um::handleapi::SetHandleInformation;
 use winapi::um::winsock2 as sock;
 
+// Windows doesn't expose `TCP_NODELAY` and `IPPROTO_IP` through `ws2def`, so
+// they're defined here to match the Winsock headers.
+const TCP_NODELAY: c_int = 0x0001;
+const IPPROTO_IP: c_int = 0;
+
 use super::Addr;
 
+// Windows 10+ supports `AF_UNIX`, but winapi doesn't expose the family or
+// `sockaddr_un` yet, so both are defined here to match the Unix headers.
+pub const AF_UNIX: c_int = 1;
+
+#[repr(C)]
+pub struct sockaddr_un {
+    pub sun_family: u16,
+    pub sun_path: [u8; 108],
+}
+
 // Used in `Addr`
 pub use winapi::shared::ws2def::{SOCKADDR as sockaddr, SOCKADDR_STORAGE as sockaddr_storage};
 pub use winapi::um::ws2tcpip::socklen_t;
 // Used in `Domain`.
 pub use winapi::shared::ws2def::{AF_INET, AF_INET6};
 // Used in `Type`.
-pub use winapi::shared::ws2def::SOCK_STREAM;
+pub use winapi::shared::ws2def::{SOCK_DGRAM, SOCK_STREAM};
 // Used in `Protocol`.
 pub const IPPROTO_TCP: c_int = winapi::shared::ws2def::IPPROTO_TCP as c_int;
+pub const IPPROTO_UDP: c_int = winapi::shared::ws2def::IPPROTO_UDP as c_int;
 
 const HANDLE_FLAG_INHERIT: DWORD = 0x00000001;
 const WSA_FLAG_OVERLAPPED: DWORD = 0x01;
 
+impl super::Domain {
+    // Domain for Unix socket communication.
+    pub fn unix() -> Self {
+        Self(AF_UNIX)
+    }
+}
+
+impl Addr {
+    // Constructs a `Addr` with the family `AF_UNIX` and the provided path.
+    // Returns an error if the path isn't shorter than `SUN_LEN` or isn't
+    // valid UTF-8.
+    pub fn unix<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        use std::mem::zeroed;
+
+        unsafe {
+            let mut addr = zeroed::<sockaddr_un>();
+            addr.sun_family = AF_UNIX as u16;
+
+            let bytes = path.as_ref().to_str().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "path must be valid UTF-8")
+            })?;
+            let bytes = bytes.as_bytes();
+
+            // Unlike Linux, Windows `AF_UNIX` sockets have no abstract
+            // namespace, so a leading NUL is just an ordinary path byte and
+            // every path needs a null terminator.
+            if bytes.len() >= addr.sun_path.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "path must be shorter than SUN_LEN",
+                ));
+            }
+
+            for (dst, src) in addr.sun_path.iter_mut().zip(bytes) {
+                *dst = *src;
+            }
+            // null byte for pathname is already there since we zeroed up front
+
+            let base = &addr as *const _ as usize;
+            let path = &addr.sun_path as *const _ as usize;
+            let sun_path_offset = path - base;
+
+            let len = sun_path_offset + bytes.len() + 1;
+            Ok(Self::from_raw_parts(
+                &addr as *const sockaddr_un as *const _,
+                len as socklen_t,
+            ))
+        }
+    }
+}
+
 fn init() {
     static INIT: Once = Once::new();
 
@@ -40,6 +117,20 @@ fn last_error() -> io::Error {
     io::Error::from_raw_os_error(unsafe { sock::WSAGetLastError() })
 }
 
+fn single_fd_set(socket: sock::SOCKET) -> sock::fd_set {
+    let mut set: sock::fd_set = unsafe { std::mem::zeroed() };
+    set.fd_count = 1;
+    set.fd_array[0] = socket;
+    set
+}
+
+fn to_timeval(timeout: Duration) -> sock::timeval {
+    sock::timeval {
+        tv_sec: timeout.as_secs() as i32,
+        tv_usec: timeout.subsec_micros() as i32,
+    }
+}
+
 #[derive(Debug)]
 pub struct Socket {
     socket: sock::SOCKET,
@@ -82,6 +173,183 @@ impl Socket {
             }
         }
     }
+
+    pub fn bind(&self, addr: Addr) -> io::Result<()> {
+        unsafe {
+            if sock::bind(self.socket, addr.as_ptr(), addr.len()) == 0 {
+                Ok(())
+            } else {
+                Err(last_error())
+            }
+        }
+    }
+
+    pub fn listen(&self, backlog: c_int) -> io::Result<()> {
+        unsafe {
+            if sock::listen(self.socket, backlog) == 0 {
+                Ok(())
+            } else {
+                Err(last_error())
+            }
+        }
+    }
+
+    pub fn accept(&self) -> io::Result<(Self, Addr)> {
+        unsafe {
+            let mut storage = MaybeUninit::<sockaddr_storage>::uninit();
+            let mut len = std::mem::size_of::<sockaddr_storage>() as socklen_t;
+            let socket = match sock::accept(self.socket, storage.as_mut_ptr() as *mut _, &mut len)
+            {
+                sock::INVALID_SOCKET => return Err(last_error()),
+                socket => socket,
+            };
+
+            // Set no inherit.
+            if SetHandleInformation(socket as HANDLE, HANDLE_FLAG_INHERIT, 0) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // Put socket into nonblocking mode.
+            let mut nonblocking = true as c_ulong;
+            if sock::ioctlsocket(socket, sock::FIONBIO as c_int, &mut nonblocking) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            let addr = Addr::from_raw_parts(storage.as_ptr() as *const _, len);
+            Ok((Self::from_raw_socket(socket as RawSocket), addr))
+        }
+    }
+
+    pub fn connect_timeout(&self, addr: Addr, timeout: Duration) -> io::Result<()> {
+        match self.connect(addr) {
+            Ok(()) => return Ok(()),
+            Err(e)
+                if e.raw_os_error() == Some(sock::WSAEWOULDBLOCK)
+                    || e.raw_os_error() == Some(sock::WSAEINPROGRESS) => {}
+            Err(e) => return Err(e),
+        }
+
+        let mut remaining = timeout;
+        loop {
+            let mut write_fds = single_fd_set(self.socket);
+            // Windows reports a failed non-blocking connect through the
+            // except fd set rather than an error return from `select`.
+            let mut except_fds = single_fd_set(self.socket);
+            let tv = to_timeval(remaining);
+
+            let start = Instant::now();
+            let ret = unsafe {
+                sock::select(
+                    0,
+                    ptr::null_mut(),
+                    &mut write_fds,
+                    &mut except_fds,
+                    &tv,
+                )
+            };
+            remaining = remaining.saturating_sub(start.elapsed());
+
+            match ret {
+                sock::SOCKET_ERROR => return Err(last_error()),
+                0 => return Err(io::ErrorKind::TimedOut.into()),
+                _ => break,
+            }
+        }
+
+        self.take_error()
+    }
+
+    fn take_error(&self) -> io::Result<()> {
+        unsafe {
+            let mut err: c_int = 0;
+            let mut len = std::mem::size_of::<c_int>() as c_int;
+            if sock::getsockopt(
+                self.socket,
+                SOL_SOCKET,
+                SO_ERROR,
+                &mut err as *mut c_int as *mut i8,
+                &mut len,
+            ) != 0
+            {
+                return Err(last_error());
+            }
+
+            if err == 0 {
+                Ok(())
+            } else {
+                Err(io::Error::from_raw_os_error(err))
+            }
+        }
+    }
+
+    pub fn set_reuse_address(&self, reuse: bool) -> io::Result<()> {
+        self.setsockopt(SOL_SOCKET, SO_REUSEADDR, reuse as c_int)
+    }
+
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.setsockopt(IPPROTO_TCP, TCP_NODELAY, nodelay as c_int)
+    }
+
+    // Per-socket keepalive idle/interval time isn't a plain sockopt on
+    // Windows, so it's set via the `SIO_KEEPALIVE_VALS` ioctl instead.
+    pub fn set_keepalive(&self, keepalive: Option<Duration>) -> io::Result<()> {
+        self.setsockopt(SOL_SOCKET, SO_KEEPALIVE, keepalive.is_some() as c_int)?;
+
+        if let Some(time) = keepalive {
+            let millis = time.as_millis() as c_ulong;
+            let mut vals = tcp_keepalive {
+                onoff: 1,
+                keepalivetime: millis,
+                keepaliveinterval: millis,
+            };
+            let mut bytes_returned: DWORD = 0;
+            let ret = unsafe {
+                sock::WSAIoctl(
+                    self.socket,
+                    SIO_KEEPALIVE_VALS,
+                    &mut vals as *mut _ as *mut _,
+                    std::mem::size_of::<tcp_keepalive>() as DWORD,
+                    ptr::null_mut(),
+                    0,
+                    &mut bytes_returned,
+                    ptr::null_mut(),
+                    None,
+                )
+            };
+            if ret != 0 {
+                return Err(last_error());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
+        self.setsockopt(IPPROTO_IP, IP_TTL, ttl as c_int)
+    }
+
+    pub fn set_recv_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.setsockopt(SOL_SOCKET, SO_RCVBUF, size as c_int)
+    }
+
+    pub fn set_send_buffer_size(&self, size: usize) -> io::Result<()> {
+        self.setsockopt(SOL_SOCKET, SO_SNDBUF, size as c_int)
+    }
+
+    fn setsockopt(&self, level: c_int, name: c_int, value: c_int) -> io::Result<()> {
+        unsafe {
+            if sock::setsockopt(
+                self.socket,
+                level,
+                name,
+                &value as *const c_int as *const i8,
+                std::mem::size_of::<c_int>() as c_int,
+            ) == 0
+            {
+                Ok(())
+            } else {
+                Err(last_error())
+            }
+        }
+    }
 }
 
 impl Drop for Socket {
@@ -119,3 +387,9 @@ impl From<Socket> for TcpStream {
         unsafe { Self::from_raw_socket(socket.into_raw_socket()) }
     }
 }
+
+impl From<Socket> for UdpSocket {
+    fn from(socket: Socket) -> Self {
+        unsafe { Self::from_raw_socket(socket.into_raw_socket()) }
+    }
+}